@@ -1,7 +1,9 @@
 //! Polynomials
 use crate::integers_mod;
-use crate::traits::{IntegerModN, RingType};
+use crate::traits::{FieldType, IntegerModN, RingType, TwoAdicField};
 
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 use std::rc::Rc;
 
@@ -48,14 +50,248 @@ impl<T> Polynomial<T> {
         }
     }
 
-    /// multiply, assuming rhs has lower or equal degree to self.
-    fn mul_pad_second(self, rhs: Self) -> Self {
-        todo!();
+}
+
+impl<T: RingType + Clone> Polynomial<T> {
+    /// The coefficients of `self`, trimmed to exactly `deg() + 1` entries (no trailing
+    /// padding beyond the leading term).
+    fn trimmed_coeffs(&self) -> Vec<T> {
+        self.coeffs()[..=(self.deg() as usize)].to_vec()
+    }
+
+    /// Multiplies every coefficient by `factor`.
+    pub(crate) fn scale(&self, factor: &T) -> Self {
+        Self::from(
+            self.trimmed_coeffs()
+                .into_iter()
+                .map(|coeff| coeff * factor.clone())
+                .collect::<Vec<T>>(),
+        )
+    }
+
+    /// Multiplies `self` by `rhs`, truncating the product to its lowest `terms`
+    /// coefficients, i.e. reducing modulo `x^terms`. This lets the fast-division path
+    /// below multiply without paying for high-order terms it will discard anyway.
+    fn mul_trunc(&self, rhs: &Self, terms: u64) -> Self {
+        let lhs_coeffs = self.trimmed_coeffs();
+        let rhs_coeffs = rhs.trimmed_coeffs();
+        let terms = terms as usize;
+
+        let mut result = vec![T::zero(); terms];
+        for (i, a) in lhs_coeffs.iter().enumerate() {
+            if i >= terms {
+                break;
+            }
+            for (j, b) in rhs_coeffs.iter().enumerate() {
+                if i + j >= terms {
+                    break;
+                }
+                result[i + j] = result[i + j].clone() + a.clone() * b.clone();
+            }
+        }
+        Self::from(result)
+    }
+
+    /// Reverses the coefficients of `self` up to `deg()`, i.e. computes
+    /// `x^deg(self) * self(1/x)`.
+    fn reversed(&self) -> Self {
+        let mut coeffs = self.trimmed_coeffs();
+        coeffs.reverse();
+        Self::from(coeffs)
+    }
+
+    /// Truncates `self` to its lowest `terms` coefficients (reduces modulo `x^terms`).
+    fn truncated(&self, terms: u64) -> Self {
+        let terms = terms as usize;
+        let coeffs = self.trimmed_coeffs();
+        Self::from(coeffs.into_iter().take(terms).collect::<Vec<T>>())
+    }
+
+    /// Evaluates `self` at `x` via Horner's rule: folding from the leading coefficient,
+    /// `acc = acc * x + coeff`.
+    pub fn eval(&self, x: T) -> T {
+        self.trimmed_coeffs()
+            .into_iter()
+            .rev()
+            .fold(T::zero(), |acc, coeff| acc * x.clone() + coeff)
+    }
+
+    /// Evaluates `self` at every point in `xs`.
+    pub fn eval_many(&self, xs: &[T]) -> Vec<T> {
+        xs.iter().map(|x| self.eval(x.clone())).collect()
+    }
+
+    /// Builds `prod_i (x - r_i)` by repeatedly multiplying in linear factors. Over a field
+    /// this is exactly the signed elementary-symmetric (Vieta) polynomial of `roots`.
+    pub fn from_roots(roots: Vec<T>) -> Self {
+        roots.into_iter().fold(Self::one(), |acc, root| {
+            acc * Self::from(vec![-root, T::one()])
+        })
+    }
+
+    /// Coefficient-wise subtraction, used by algorithms in this module (and by the
+    /// `poly_mod!` macro) that work directly on `T`'s ring operations rather than through
+    /// the `Sub` operator.
+    pub(crate) fn sub_coeffwise(&self, rhs: &Self) -> Self {
+        let lhs = self.trimmed_coeffs();
+        let rhs_coeffs = rhs.trimmed_coeffs();
+        let len = lhs.len().max(rhs_coeffs.len());
+
+        let mut result = Vec::with_capacity(len);
+        for i in 0..len {
+            let a = lhs.get(i).cloned().unwrap_or_else(T::zero);
+            let b = rhs_coeffs.get(i).cloned().unwrap_or_else(T::zero);
+            result.push(a - b);
+        }
+        Self::from(result)
+    }
+
+    /// Coefficient-wise addition, the counterpart to [`Self::sub_coeffwise`].
+    pub(crate) fn add_coeffwise(&self, rhs: &Self) -> Self {
+        let lhs = self.trimmed_coeffs();
+        let rhs_coeffs = rhs.trimmed_coeffs();
+        let len = lhs.len().max(rhs_coeffs.len());
+
+        let mut result = Vec::with_capacity(len);
+        for i in 0..len {
+            let a = lhs.get(i).cloned().unwrap_or_else(T::zero);
+            let b = rhs_coeffs.get(i).cloned().unwrap_or_else(T::zero);
+            result.push(a + b);
+        }
+        Self::from(result)
+    }
+
+    /// Negates every coefficient.
+    pub(crate) fn negate(&self) -> Self {
+        Self::from(
+            self.trimmed_coeffs()
+                .into_iter()
+                .map(|c| -c)
+                .collect::<Vec<T>>(),
+        )
+    }
+
+    /// The formal derivative: maps coefficient `a_i` (`i >= 1`) to `i * a_i`, shifted down
+    /// one degree, where `i * a_i` denotes repeated addition of `a_i` to itself `i` times
+    /// (via binary doubling), since `T` need not support multiplication by a bare integer.
+    pub fn derivative(&self) -> Self {
+        if self.deg() == 0 {
+            return Self::zero();
+        }
+        let result = self
+            .trimmed_coeffs()
+            .into_iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, a)| nat_mul(i as u64, a))
+            .collect::<Vec<T>>();
+        Self::from(result)
+    }
+
+    /// `self` raised to the `exp`-th power, via binary exponentiation over `Mul`.
+    pub fn pow(&self, exp: u64) -> Self {
+        let mut base = self.clone();
+        let mut exp = exp;
+        let mut result = Self::one();
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base.clone();
+            }
+            base = base.clone() * base;
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+/// `n * elem`, i.e. `elem` added to itself `n` times, computed via binary doubling so that
+/// it costs `O(log n)` ring operations rather than `n`.
+fn nat_mul<T: RingType + Clone>(n: u64, elem: T) -> T {
+    let mut result = T::zero();
+    let mut base = elem;
+    let mut n = n;
+
+    while n > 0 {
+        if n & 1 == 1 {
+            result = result + base.clone();
+        }
+        base = base.clone() + base;
+        n >>= 1;
+    }
+    result
+}
+
+impl<T: FieldType + Clone> Polynomial<T> {
+    /// Divides through by the leading coefficient's inverse, yielding a monic polynomial
+    /// (leading coefficient 1) with the same roots.
+    pub fn to_monic(&self) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+        let lead_inv = self.trimmed_coeffs()[self.deg() as usize].inverse();
+        self.scale(&lead_inv)
+    }
+
+    /// The monic GCD of `self` and `other`, computed via the Euclidean algorithm on top of
+    /// `div_rem`.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let mut a = self.clone();
+        let mut b = other.clone();
+
+        while !b.is_zero() {
+            let (_, r) = a.div_rem(&b);
+            a = b;
+            b = r;
+        }
+        a.to_monic()
     }
 
-    /// add, assuming rhs has lower or equal degree to self.
-    fn add_pad_second(self, rhs: Self) -> Self {
-        todo!();
+    /// Extended Euclidean algorithm: returns `(g, s, t)` with `g = gcd(self, other)` and
+    /// `s * self + t * other == g`.
+    pub fn extended_gcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (mut old_r, mut r) = (self.clone(), other.clone());
+        let (mut old_s, mut s) = (Self::one(), Self::zero());
+        let (mut old_t, mut t) = (Self::zero(), Self::one());
+
+        while !r.is_zero() {
+            let (q, rem) = old_r.div_rem(&r);
+
+            old_r = std::mem::replace(&mut r, rem);
+
+            let new_s = old_s.sub_coeffwise(&(q.clone() * s.clone()));
+            old_s = std::mem::replace(&mut s, new_s);
+
+            let new_t = old_t.sub_coeffwise(&(q * t.clone()));
+            old_t = std::mem::replace(&mut t, new_t);
+        }
+
+        let lead_inv = old_r.trimmed_coeffs()[old_r.deg() as usize].inverse();
+        (
+            old_r.scale(&lead_inv),
+            old_s.scale(&lead_inv),
+            old_t.scale(&lead_inv),
+        )
+    }
+
+    /// The antiderivative with zero constant term: maps coefficient `a_i` to
+    /// `a_i / (i + 1)`, shifted up one degree. Gated on `T` being a field, since
+    /// integration introduces denominators that needn't exist in a general ring.
+    ///
+    /// # Panics
+    /// Panics if some `i + 1` is additively zero in `T` (e.g. integrating a term of
+    /// degree `p - 1` or higher over a field of positive characteristic `p`), since that
+    /// denominator has no inverse.
+    pub fn integral(&self) -> Self {
+        if self.is_zero() {
+            return Self::zero();
+        }
+        let mut result = vec![T::zero()];
+        for (i, a) in self.trimmed_coeffs().into_iter().enumerate() {
+            let denom_inv = nat_mul((i + 1) as u64, T::one()).inverse();
+            result.push(a * denom_inv);
+        }
+        Self::from(result)
     }
 }
 
@@ -123,7 +359,7 @@ impl<T: Eq> PartialEq for Polynomial<T> {
 impl<T: Eq> Eq for Polynomial<T> {}
 
 /// The 1 of the polynomial ring is the 1 of its coeffient ring.
-impl<T: One + Add + Zero + Eq> One for Polynomial<T> {
+impl<T: RingType + Clone> One for Polynomial<T> {
     fn one() -> Self {
         Self::from(vec![T::one()])
     }
@@ -138,7 +374,7 @@ impl<T: One + Add + Zero + Eq> One for Polynomial<T> {
 }
 
 /// The 0 of the polynomial ring is the 0 of its coefficient ring.
-impl<T: Zero + Eq> Zero for Polynomial<T> {
+impl<T: RingType + Clone> Zero for Polynomial<T> {
     fn zero() -> Self {
         Self::from(vec![T::zero()])
     }
@@ -159,38 +395,27 @@ impl<T: RingType> RingType for Polynomial<T> {}
 /// \sum\_{i=0}^n a\_i x^i + \sum\_{j=0} b\_j x^j = \sum\_{i=0}^{\max(n,m)} (a_i + b_i) x^i
 /// $$
 /// where coefficients beyond the degree of the polynomial are taken to be zero.
-impl<T: Add + Zero> Add for Polynomial<T> {
+impl<T: RingType + Clone> Add for Polynomial<T> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self {
-        if Self::compare_deg(&self, &rhs) {
-            // pad rhs
-            Self::add_pad_second(self, rhs)
-        } else {
-            // pad self
-            Self::add_pad_second(rhs, self)
-        }
+        self.add_coeffwise(&rhs)
     }
 }
 
-impl<T: Neg> Neg for Polynomial<T> {
+impl<T: RingType + Clone> Neg for Polynomial<T> {
     type Output = Self;
 
     fn neg(self) -> Self {
-        /*
-        let neg_coeffs = self.coeffs().into_iter().map(|elem| -elem).collect::<Vec<T>>();
-
-        Self::from(neg_coeffs)
-        */
-        todo!();
+        self.negate()
     }
 }
 
-impl<T: Add + Sub + Neg + Zero> Sub for Polynomial<T> {
+impl<T: RingType + Clone> Sub for Polynomial<T> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self {
-        self + (-rhs)
+        self.sub_coeffwise(&rhs)
     }
 }
 
@@ -199,16 +424,15 @@ impl<T: Add + Sub + Neg + Zero> Sub for Polynomial<T> {
 /// \Big(\sum\_{i=0}^n a\_i x^i\Big)\Big(\sum\_{j=0}^m b\_j x^j\Big) =
 /// \sum\_{k=0}^{n+m} \sum\_{i = 0}^k a\_i b\_{k-i} x^k
 /// $$
-impl<T: Zero + Mul + Add> Mul for Polynomial<T> {
+impl<T: RingType + Clone> Mul for Polynomial<T> {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self {
-        /*
         //degrees of the polynomials lhs = n, rhs = m
-        let lhs_vec = self.coeffs();
-        let rhs_vec = rhs.coeffs();
-        let n = &lhs_vec.len() - 1;
-        let m = &rhs_vec.len() - 1;
+        let lhs_vec = self.trimmed_coeffs();
+        let rhs_vec = rhs.trimmed_coeffs();
+        let n = lhs_vec.len() - 1;
+        let m = rhs_vec.len() - 1;
 
         // the formula:
         //\sum_{k=0}^n a_k x^k \sum_{k=0}^m b_k x^k = \sum_{k=0}^{n+m} \sum_{i=0}^k a_i b_{k-i} x^k
@@ -229,31 +453,720 @@ impl<T: Zero + Mul + Add> Mul for Polynomial<T> {
             result.push(kth_coeff);
         }
         Self::from(result)
-        */
-        todo!();
     }
 }
 
-impl<T: Div> Div for Polynomial<T> {
+/// Recursive radix-2 NTT: evaluates `coeffs` (length a power of two) at the powers of
+/// `root`, a primitive `len(coeffs)`-th root of unity.
+fn fft<T: RingType + Clone>(coeffs: &[T], root: &T) -> Vec<T> {
+    let len = coeffs.len();
+    if len == 1 {
+        return coeffs.to_vec();
+    }
+
+    let even: Vec<T> = coeffs.iter().step_by(2).cloned().collect();
+    let odd: Vec<T> = coeffs.iter().skip(1).step_by(2).cloned().collect();
+
+    let root_sq = root.clone() * root.clone();
+    let even_fft = fft(&even, &root_sq);
+    let odd_fft = fft(&odd, &root_sq);
+
+    let mut result = vec![T::zero(); len];
+    let mut twiddle = T::one();
+    for i in 0..(len / 2) {
+        let t = twiddle.clone() * odd_fft[i].clone();
+        result[i] = even_fft[i].clone() + t.clone();
+        result[i + len / 2] = even_fft[i].clone() - t;
+        twiddle = twiddle * root.clone();
+    }
+    result
+}
+
+/// A polynomial in point-value form: its values at the powers `g^0, .., g^{N-1}` of a
+/// primitive `N`-th root of unity `g`, `N` a power of two. Pointwise multiplication here
+/// corresponds to polynomial multiplication modulo `x^N - 1`, which is ordinary
+/// polynomial multiplication provided `N` is large enough to hold the true product.
+#[derive(Debug, Clone)]
+pub struct PolynomialValues<T> {
+    values: Rc<Vec<T>>,
+}
+
+impl<T> PolynomialValues<T> {
+    pub fn values(&self) -> Rc<Vec<T>> {
+        self.values.clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl<T: TwoAdicField + Clone> PolynomialValues<T> {
+    /// Pointwise multiplication of two value-form polynomials sharing an evaluation domain.
+    pub fn mul(&self, rhs: &Self) -> Self {
+        assert_eq!(
+            self.len(),
+            rhs.len(),
+            "value-form polynomials must share an evaluation domain to multiply pointwise"
+        );
+        let product = self
+            .values()
+            .iter()
+            .zip(rhs.values().iter())
+            .map(|(a, b)| a.clone() * b.clone())
+            .collect::<Vec<T>>();
+        Self {
+            values: Rc::new(product),
+        }
+    }
+
+    /// Converts back to coefficient form via the inverse NTT.
+    pub fn from_values(&self) -> Polynomial<T> {
+        let len = self.len();
+        let log_len = len.trailing_zeros();
+        let root_inv = T::primitive_root_of_unity(log_len).inverse();
+
+        let mut coeffs = fft(&self.values(), &root_inv);
+        let len_as_t = (0..len).fold(T::zero(), |acc, _| acc + T::one());
+        let len_inv = len_as_t.inverse();
+        for coeff in coeffs.iter_mut() {
+            *coeff = coeff.clone() * len_inv.clone();
+        }
+
+        Polynomial::from(coeffs)
+    }
+}
+
+impl<T: TwoAdicField + Clone> Polynomial<T> {
+    /// Converts `self` to point-value form at the `len`-th roots of unity. `len` must be a
+    /// power of two at least `deg(self) + 1`.
+    pub fn to_values(&self, len: usize) -> PolynomialValues<T> {
+        assert!(
+            len.is_power_of_two(),
+            "evaluation domain length must be a power of two"
+        );
+        assert!(
+            len as u64 >= self.deg() + 1,
+            "evaluation domain length must be at least deg(self) + 1"
+        );
+        let log_len = len.trailing_zeros();
+        let root = T::primitive_root_of_unity(log_len);
+
+        let mut coeffs = self.trimmed_coeffs();
+        coeffs.resize(len, T::zero());
+        PolynomialValues {
+            values: Rc::new(fft(&coeffs, &root)),
+        }
+    }
+
+    /// Multiplies `self` by `rhs` in O(n log n) via NTT: evaluate both at a large-enough
+    /// power-of-two root of unity, multiply pointwise, then interpolate back.
+    pub fn mul_fft(&self, rhs: &Self) -> Self {
+        if self.is_zero() || rhs.is_zero() {
+            return Self::zero();
+        }
+        let result_len = (self.deg() + rhs.deg() + 1).next_power_of_two() as usize;
+        self.to_values(result_len)
+            .mul(&rhs.to_values(result_len))
+            .from_values()
+    }
+}
+
+/// Below what degree gap `deg(a) - deg(b)` the straightforward schoolbook division is used
+/// in preference to the Newton-iteration fast path. The fast path pays for two polynomial
+/// multiplications per doubling of precision, so it only wins once the quotient itself is
+/// long enough to amortize that cost.
+const FAST_DIV_REM_THRESHOLD: u64 = 64;
+
+impl<T: FieldType + Clone> Polynomial<T> {
+    /// Divides `self` by `divisor`, returning `(quotient, remainder)` with
+    /// `self == quotient * divisor + remainder` and `deg(remainder) < deg(divisor)`.
+    ///
+    /// Requires the leading coefficient of `divisor` to be invertible in `T`.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is the zero polynomial.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        if divisor.is_zero() {
+            panic!("polynomial division by the zero polynomial");
+        }
+        if self.is_zero() {
+            return (Self::zero(), Self::zero());
+        }
+        if self.deg() < divisor.deg() {
+            return (Self::zero(), self.clone());
+        }
+        if divisor.deg() == 0 {
+            let lead_inv = divisor.trimmed_coeffs()[0].inverse();
+            return (self.scale(&lead_inv), Self::zero());
+        }
+
+        if self.deg() - divisor.deg() >= FAST_DIV_REM_THRESHOLD {
+            self.fast_div_rem(divisor)
+        } else {
+            self.schoolbook_div_rem(divisor)
+        }
+    }
+
+    /// Long division: for descending `k`, subtract `q_k * x^k * divisor` from the running
+    /// remainder so that its degree-`(m + k)` coefficient vanishes.
+    fn schoolbook_div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let n = self.deg();
+        let m = divisor.deg();
+        let divisor_coeffs = divisor.trimmed_coeffs();
+        let lead_inv = divisor_coeffs[m as usize].inverse();
+
+        let mut remainder = self.trimmed_coeffs();
+        let mut quotient = vec![T::zero(); (n - m + 1) as usize];
+
+        for k in (0..=(n - m)).rev() {
+            let q_k = remainder[(m + k) as usize].clone() * lead_inv.clone();
+            if !q_k.is_zero() {
+                for (i, divisor_coeff) in divisor_coeffs.iter().enumerate() {
+                    let idx = (k as usize) + i;
+                    remainder[idx] =
+                        remainder[idx].clone() - q_k.clone() * divisor_coeff.clone();
+                }
+            }
+            quotient[k as usize] = q_k;
+        }
+
+        (Self::from(quotient), Self::from(remainder))
+    }
+
+    /// Computes the inverse of `self` modulo `x^precision`, via Newton iteration.
+    /// Requires `self`'s constant term to be invertible. Starts from `g_0 = 1/self[0]`
+    /// and doubles precision each step via `g_{i+1} = g_i * (2 - self * g_i) mod x^{2^{i+1}}`.
+    fn inverse_mod_xk(&self, precision: u64) -> Self {
+        let const_inv = self.trimmed_coeffs()[0].inverse();
+        let mut inverse = Self::from(vec![const_inv]);
+        let mut current_precision = 1_u64;
+
+        while current_precision < precision {
+            current_precision = (current_precision * 2).min(precision);
+            let two = Self::from(vec![T::one() + T::one()]);
+            let correction = two.sub_coeffwise(&self.mul_trunc(&inverse, current_precision));
+            inverse = inverse.mul_trunc(&correction, current_precision);
+        }
+
+        inverse
+    }
+
+    /// Reversed-polynomial Newton-inversion division: reverse both operands, invert
+    /// `rev(divisor)` modulo `x^{n-m+1}`, multiply by `rev(self)` and truncate to recover
+    /// the reversed quotient, then reverse back and recover the remainder as
+    /// `self - quotient * divisor`.
+    fn fast_div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let n = self.deg();
+        let m = divisor.deg();
+        let quotient_len = n - m + 1;
+
+        let rev_self = self.reversed();
+        let rev_divisor = divisor.reversed();
+        let rev_divisor_inv = rev_divisor.inverse_mod_xk(quotient_len);
+
+        let rev_quotient = rev_self.mul_trunc(&rev_divisor_inv, quotient_len);
+        let mut quotient_coeffs = rev_quotient.truncated(quotient_len).trimmed_coeffs();
+        quotient_coeffs.resize(quotient_len as usize, T::zero());
+        quotient_coeffs.reverse();
+        let quotient = Self::from(quotient_coeffs);
+
+        // remainder = self - quotient * divisor, computed coefficient-wise up to deg(divisor).
+        let mut product = quotient.mul_trunc(divisor, m + 1).trimmed_coeffs();
+        product.resize((m + 1) as usize, T::zero());
+        let mut remainder_coeffs = self.trimmed_coeffs();
+        remainder_coeffs.resize((m + 1) as usize, T::zero());
+        for (i, p) in product.into_iter().enumerate() {
+            remainder_coeffs[i] = remainder_coeffs[i].clone() - p;
+        }
+
+        (quotient, Self::from(remainder_coeffs))
+    }
+}
+
+impl<T: FieldType + Clone> Div for Polynomial<T> {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self {
-        todo!();
+        self.div_rem(&rhs).0
     }
 }
 
-impl<T: Rem> Rem for Polynomial<T> {
+impl<T: FieldType + Clone> Rem for Polynomial<T> {
     type Output = Self;
 
     fn rem(self, rhs: Self) -> Self {
-        todo!();
+        self.div_rem(&rhs).1
     }
 }
 
+/// Generates a new type implementing arithmetic in the quotient ring `T[x]/(f(x))` (e.g. a
+/// cyclotomic ring `T[x]/(x^n - 1)`), the polynomial analogue of [`crate::integers_mod`]:
+/// elements are reduced by the fixed modulus polynomial `f` (via [`Polynomial::div_rem`])
+/// after every `Add` and `Mul`. When `f` is irreducible, every nonzero element also has a
+/// multiplicative inverse, computed via [`Polynomial::extended_gcd`], making the generated
+/// type a field extension of `T` (e.g. a finite-field extension).
+#[macro_export]
+macro_rules! poly_mod {
+    ($name:ident, $coeff_ty:ty, $modulus:expr) => {
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            value: $crate::poly::Polynomial<$coeff_ty>,
+        }
+
+        impl $name {
+            /// The fixed modulus polynomial `f` defining this quotient ring.
+            pub fn modulus() -> $crate::poly::Polynomial<$coeff_ty> {
+                $modulus
+            }
+
+            pub fn new(value: $crate::poly::Polynomial<$coeff_ty>) -> Self {
+                let (_, reduced) = value.div_rem(&Self::modulus());
+                Self { value: reduced }
+            }
+
+            pub fn value(&self) -> $crate::poly::Polynomial<$coeff_ty> {
+                self.value.clone()
+            }
+
+            /// The multiplicative inverse of `self`, assuming `modulus()` is irreducible
+            /// and `self` is nonzero. Computed as the Bezout coefficient `s`, normalized to
+            /// cancel the (necessarily degree-zero) gcd, in `s * self + t * modulus() = gcd`.
+            pub fn inverse(&self) -> Self {
+                let (g, s, _) = self.value.extended_gcd(&Self::modulus());
+                let lead_inv = g.coeffs()[0].inverse();
+                Self::new(s.scale(&lead_inv))
+            }
+        }
+
+        impl ::std::ops::Add for $name {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Self::new(self.value.add_coeffwise(&rhs.value))
+            }
+        }
+
+        impl ::std::ops::Sub for $name {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Self::new(self.value.sub_coeffwise(&rhs.value))
+            }
+        }
+
+        impl ::std::ops::Neg for $name {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self::new(self.value.negate())
+            }
+        }
+
+        impl ::std::ops::Mul for $name {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self {
+                Self::new(self.value * rhs.value)
+            }
+        }
+
+        impl ::num_traits::identities::Zero for $name {
+            fn zero() -> Self {
+                Self::new($crate::poly::Polynomial::zero())
+            }
+            fn is_zero(&self) -> bool {
+                self.value.is_zero()
+            }
+        }
+
+        impl ::num_traits::identities::One for $name {
+            fn one() -> Self {
+                Self::new($crate::poly::Polynomial::one())
+            }
+            fn is_one(&self) -> bool {
+                self.value.is_one()
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.value == other.value
+            }
+        }
+        impl Eq for $name {}
+
+        impl $crate::traits::RingType for $name {}
+
+        impl $crate::traits::FieldType for $name {
+            fn inverse(&self) -> Self {
+                Self::inverse(self)
+            }
+        }
+    };
+}
+
+/// A monomial order, used to pick a leading term when comparing terms of a [`MultiPoly`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonomialOrder {
+    /// Compares exponent vectors componentwise, left to right.
+    Lex,
+    /// Compares total degree first, breaking ties with `Lex`.
+    GradedLex,
+    /// Compares total degree first, breaking ties by preferring the monomial with the
+    /// *smaller* exponent at the last variable where they differ.
+    GradedRevLex,
+}
+
+fn monomial_degree(exponents: &[u32]) -> u32 {
+    exponents.iter().sum()
+}
+
+fn monomial_lcm(a: &[u32], b: &[u32]) -> Vec<u32> {
+    a.iter().zip(b.iter()).map(|(x, y)| *x.max(y)).collect()
+}
+
+fn monomial_divides(divisor: &[u32], dividend: &[u32]) -> bool {
+    divisor.iter().zip(dividend.iter()).all(|(d, n)| d <= n)
+}
+
+/// Subtracts exponents of `b` from `a`; assumes `b` divides `a`.
+fn monomial_div(a: &[u32], b: &[u32]) -> Vec<u32> {
+    a.iter().zip(b.iter()).map(|(x, y)| x - y).collect()
+}
+
+fn compare_monomials(order: MonomialOrder, a: &[u32], b: &[u32]) -> Ordering {
+    match order {
+        MonomialOrder::Lex => a.cmp(b),
+        MonomialOrder::GradedLex => monomial_degree(a).cmp(&monomial_degree(b)).then_with(|| a.cmp(b)),
+        MonomialOrder::GradedRevLex => {
+            monomial_degree(a).cmp(&monomial_degree(b)).then_with(|| {
+                for (x, y) in a.iter().zip(b.iter()).rev() {
+                    if x != y {
+                        // smaller trailing exponent is considered larger
+                        return y.cmp(x);
+                    }
+                }
+                Ordering::Equal
+            })
+        }
+    }
+}
+
+/// A multivariate polynomial over `T`, represented as a sparse map from monomial exponent
+/// vectors (one exponent per variable) to coefficients, compared under a fixed
+/// [`MonomialOrder`]. Modelled after the nested `Polynomial<Polynomial<T>>` sketches of
+/// `Z[x, y]`, but as a genuine multivariate representation with a selectable term order,
+/// which the `Polynomial<Polynomial<T>>` encoding doesn't give a direct way to pick.
+#[derive(Debug, Clone)]
+pub struct MultiPoly<T> {
+    terms: HashMap<Vec<u32>, T>,
+    order: MonomialOrder,
+}
+
+impl<T: RingType + Clone> MultiPoly<T> {
+    /// The zero polynomial under the given order.
+    pub fn zero(order: MonomialOrder) -> Self {
+        Self {
+            terms: HashMap::new(),
+            order,
+        }
+    }
+
+    /// A single-term polynomial `coeff * x^exponents`, under the given order.
+    pub fn monomial(exponents: Vec<u32>, coeff: T, order: MonomialOrder) -> Self {
+        let mut terms = HashMap::new();
+        if !coeff.is_zero() {
+            terms.insert(exponents, coeff);
+        }
+        Self { terms, order }
+    }
+
+    pub fn order(&self) -> MonomialOrder {
+        self.order
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// The leading `(exponents, coefficient)` term under `self`'s monomial order, or
+    /// `None` if `self` is zero.
+    pub fn leading_term(&self) -> Option<(Vec<u32>, T)> {
+        self.terms
+            .iter()
+            .max_by(|(a, _), (b, _)| compare_monomials(self.order, a, b))
+            .map(|(mono, coeff)| (mono.clone(), coeff.clone()))
+    }
+
+    /// Multiplies every coefficient by `factor`.
+    pub fn scale(&self, factor: &T) -> Self {
+        Self {
+            terms: self
+                .terms
+                .iter()
+                .map(|(mono, coeff)| (mono.clone(), coeff.clone() * factor.clone()))
+                .collect(),
+            order: self.order,
+        }
+    }
+
+    /// Multiplies `self` by the monomial `x^exponents`, shifting every term.
+    pub fn shift(&self, exponents: &[u32]) -> Self {
+        Self {
+            terms: self
+                .terms
+                .iter()
+                .map(|(mono, coeff)| {
+                    let shifted = mono.iter().zip(exponents.iter()).map(|(a, b)| a + b).collect();
+                    (shifted, coeff.clone())
+                })
+                .collect(),
+            order: self.order,
+        }
+    }
+
+    /// Drops the term at `exponents`, if present.
+    fn without_term(&self, exponents: &[u32]) -> Self {
+        let mut terms = self.terms.clone();
+        terms.remove(exponents);
+        Self {
+            terms,
+            order: self.order,
+        }
+    }
+
+    /// The S-polynomial of `f` and `g`:
+    /// `(lcm(LM(f), LM(g)) / LT(f)) * f - (lcm(LM(f), LM(g)) / LT(g)) * g`,
+    /// which by construction cancels the leading terms of both `f` and `g`.
+    pub fn s_polynomial(f: &Self, g: &Self) -> Self
+    where
+        T: FieldType,
+    {
+        let (f_mono, f_coeff) = f.leading_term().expect("s_polynomial of a zero polynomial");
+        let (g_mono, g_coeff) = g.leading_term().expect("s_polynomial of a zero polynomial");
+        let lcm = monomial_lcm(&f_mono, &g_mono);
+
+        let f_term = f
+            .scale(&f_coeff.inverse())
+            .shift(&monomial_div(&lcm, &f_mono));
+        let g_term = g
+            .scale(&g_coeff.inverse())
+            .shift(&monomial_div(&lcm, &g_mono));
+
+        f_term - g_term
+    }
+
+    /// Reduces `self` modulo `basis`, repeatedly cancelling the leading term of the
+    /// remaining polynomial against any basis element whose leading monomial divides it.
+    /// The ideal membership test `is_member` is exactly "this reduces to zero".
+    pub fn reduce(&self, basis: &[Self]) -> Self
+    where
+        T: FieldType,
+    {
+        let mut remaining = self.clone();
+        let mut result = Self::zero(self.order);
+
+        while !remaining.is_zero() {
+            let (lead_mono, lead_coeff) = remaining.leading_term().unwrap();
+            let divisor = basis
+                .iter()
+                .filter(|g| !g.is_zero())
+                .find(|g| monomial_divides(&g.leading_term().unwrap().0, &lead_mono));
+
+            match divisor {
+                Some(g) => {
+                    let (g_mono, g_coeff) = g.leading_term().unwrap();
+                    let factor_mono = monomial_div(&lead_mono, &g_mono);
+                    let factor_coeff = lead_coeff * g_coeff.inverse();
+                    let subtrahend = g.scale(&factor_coeff).shift(&factor_mono);
+                    remaining = remaining - subtrahend;
+                }
+                None => {
+                    result = result + Self::monomial(lead_mono.clone(), lead_coeff, self.order);
+                    remaining = remaining.without_term(&lead_mono);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Ideal membership: whether `self` reduces to zero modulo `basis`.
+    pub fn is_member(&self, basis: &[Self]) -> bool
+    where
+        T: FieldType,
+    {
+        self.reduce(basis).is_zero()
+    }
+}
+
+impl<T: RingType + Clone> Add for MultiPoly<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let mut terms = self.terms;
+        for (mono, coeff) in rhs.terms {
+            let sum = match terms.remove(&mono) {
+                Some(existing) => existing + coeff,
+                None => coeff,
+            };
+            if !sum.is_zero() {
+                terms.insert(mono, sum);
+            }
+        }
+        Self {
+            terms,
+            order: self.order,
+        }
+    }
+}
+
+impl<T: RingType + Clone> Neg for MultiPoly<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            terms: self
+                .terms
+                .into_iter()
+                .map(|(mono, coeff)| (mono, -coeff))
+                .collect(),
+            order: self.order,
+        }
+    }
+}
+
+impl<T: RingType + Clone> Sub for MultiPoly<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+
+/// Computes a Groebner basis of the ideal generated by `generators`, under the monomial
+/// order of its (nonzero) elements, via Buchberger's algorithm: maintain a basis `G`;
+/// for every pair `(f, g)` in `G`, reduce their S-polynomial modulo `G`, and if the
+/// remainder is nonzero, add it to `G` and enqueue its pairs with the rest of `G`.
+/// Terminates once every pending pair reduces to zero.
+pub fn groebner_basis<T: FieldType + Clone>(generators: &[MultiPoly<T>]) -> Vec<MultiPoly<T>> {
+    let mut basis: Vec<MultiPoly<T>> = generators
+        .iter()
+        .filter(|g| !g.is_zero())
+        .cloned()
+        .collect();
+
+    let mut pairs: Vec<(usize, usize)> = Vec::new();
+    for i in 0..basis.len() {
+        for j in (i + 1)..basis.len() {
+            pairs.push((i, j));
+        }
+    }
+
+    while let Some((i, j)) = pairs.pop() {
+        let s = MultiPoly::s_polynomial(&basis[i], &basis[j]);
+        let remainder = s.reduce(&basis);
+
+        if !remainder.is_zero() {
+            let new_index = basis.len();
+            for k in 0..new_index {
+                pairs.push((k, new_index));
+            }
+            basis.push(remainder);
+        }
+    }
+
+    basis
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    /// A minimal field with a primitive 16th root of unity, used to exercise the NTT
+    /// (`mul_fft`/`to_values`/`from_values`) path: 17 is prime with 17 - 1 = 16 = 2^4, and
+    /// 3 is a primitive root mod 17.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Mod17(i64);
+
+    impl Mod17 {
+        fn new(n: i64) -> Self {
+            Self(n.rem_euclid(17))
+        }
+
+        fn pow(self, exp: u64) -> Self {
+            let mut result = Self::new(1);
+            let mut base = self;
+            let mut exp = exp;
+            while exp > 0 {
+                if exp & 1 == 1 {
+                    result = result * base;
+                }
+                base = base * base;
+                exp >>= 1;
+            }
+            result
+        }
+    }
+
+    impl Add for Mod17 {
+        type Output = Self;
+        fn add(self, rhs: Self) -> Self {
+            Self::new(self.0 + rhs.0)
+        }
+    }
+    impl Sub for Mod17 {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self {
+            Self::new(self.0 - rhs.0)
+        }
+    }
+    impl Mul for Mod17 {
+        type Output = Self;
+        fn mul(self, rhs: Self) -> Self {
+            Self::new(self.0 * rhs.0)
+        }
+    }
+    impl Neg for Mod17 {
+        type Output = Self;
+        fn neg(self) -> Self {
+            Self::new(-self.0)
+        }
+    }
+    impl Zero for Mod17 {
+        fn zero() -> Self {
+            Self(0)
+        }
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+    impl One for Mod17 {
+        fn one() -> Self {
+            Self(1)
+        }
+        fn is_one(&self) -> bool {
+            self.0 == 1
+        }
+    }
+    impl RingType for Mod17 {}
+    impl FieldType for Mod17 {
+        // Fermat's little theorem: a^(p-2) = a^-1 mod p, p = 17.
+        fn inverse(&self) -> Self {
+            self.pow(15)
+        }
+    }
+    impl TwoAdicField for Mod17 {
+        fn primitive_root_of_unity(log_n: u32) -> Self {
+            Mod17::new(3).pow(16 >> log_n)
+        }
+
+        fn two_adicity() -> u32 {
+            4
+        }
+    }
+
     #[test]
     fn test_from() {
         let vec1 = vec![1, 2, 3, 4, 0, 0, 0, 0, 0, 0];
@@ -278,6 +1191,359 @@ mod test {
         assert_eq!(Polynomial::compare_deg(&a, &b), true);
     }
 
+    #[test]
+    fn test_div_rem_schoolbook() {
+        integers_mod!(IntegerMod7, 7);
+
+        // (x + 1)^2 = x^2 + 2x + 1
+        let a = Polynomial::<IntegerMod7>::from(vec![
+            IntegerMod7::new(1),
+            IntegerMod7::new(2),
+            IntegerMod7::new(1),
+        ]);
+        let b =
+            Polynomial::<IntegerMod7>::from(vec![IntegerMod7::new(1), IntegerMod7::new(1)]);
+
+        let (q, r) = a.div_rem(&b);
+        assert_eq!(q, b.clone());
+        assert_eq!(r, Polynomial::<IntegerMod7>::zero());
+    }
+
+    #[test]
+    fn test_div_rem_lower_degree_dividend() {
+        integers_mod!(IntegerMod7, 7);
+
+        let a = Polynomial::<IntegerMod7>::from(vec![IntegerMod7::new(1)]);
+        let b =
+            Polynomial::<IntegerMod7>::from(vec![IntegerMod7::new(1), IntegerMod7::new(1)]);
+
+        let (q, r) = a.div_rem(&b);
+        assert_eq!(q, Polynomial::<IntegerMod7>::zero());
+        assert_eq!(r, a);
+    }
+
+    #[test]
+    fn test_div_rem_scalar_divisor() {
+        integers_mod!(IntegerMod7, 7);
+
+        // (2 + 4x) / 2 = 1 + 2x
+        let a = Polynomial::<IntegerMod7>::from(vec![IntegerMod7::new(2), IntegerMod7::new(4)]);
+        let b = Polynomial::<IntegerMod7>::from(vec![IntegerMod7::new(2)]);
+
+        let (q, r) = a.div_rem(&b);
+        assert_eq!(
+            q,
+            Polynomial::<IntegerMod7>::from(vec![IntegerMod7::new(1), IntegerMod7::new(2)])
+        );
+        assert_eq!(r, Polynomial::<IntegerMod7>::zero());
+    }
+
+    #[test]
+    fn test_div_rem_fast_path() {
+        integers_mod!(IntegerMod7, 7);
+
+        // quotient = x^70, divisor = x - 1; a = quotient * divisor has degree 71, so
+        // deg(a) - deg(divisor) = 70 >= FAST_DIV_REM_THRESHOLD and div_rem takes the
+        // Newton-inversion fast path.
+        let mut quotient_coeffs = vec![IntegerMod7::new(0); 70];
+        quotient_coeffs.push(IntegerMod7::new(1));
+        let quotient = Polynomial::<IntegerMod7>::from(quotient_coeffs);
+
+        let divisor =
+            Polynomial::<IntegerMod7>::from(vec![IntegerMod7::new(6), IntegerMod7::new(1)]);
+        let a = quotient.clone() * divisor.clone();
+
+        let (q, r) = a.div_rem(&divisor);
+        assert_eq!(q, quotient);
+        assert_eq!(r, Polynomial::<IntegerMod7>::zero());
+    }
+
+    #[test]
+    fn test_mul_naive() {
+        integers_mod!(IntegerMod7, 7);
+
+        // (x + 1)(x + 3) = x^2 + 4x + 3
+        let a = Polynomial::<IntegerMod7>::from(vec![IntegerMod7::new(1), IntegerMod7::new(1)]);
+        let b = Polynomial::<IntegerMod7>::from(vec![IntegerMod7::new(3), IntegerMod7::new(1)]);
+
+        let product = a * b;
+        assert_eq!(
+            product,
+            Polynomial::<IntegerMod7>::from(vec![
+                IntegerMod7::new(3),
+                IntegerMod7::new(4),
+                IntegerMod7::new(1),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_mul_fft_agrees_with_naive() {
+        // (1 + 2x + 3x^2)(4 + 5x + 6x^2) over Mod17, compared via the naive O(n^2) Mul and
+        // via mul_fft's NTT-based evaluate/pointwise-multiply/interpolate path.
+        let a = Polynomial::<Mod17>::from(vec![Mod17::new(1), Mod17::new(2), Mod17::new(3)]);
+        let b = Polynomial::<Mod17>::from(vec![Mod17::new(4), Mod17::new(5), Mod17::new(6)]);
+
+        let naive = a.clone() * b.clone();
+        let via_fft = a.mul_fft(&b);
+
+        assert_eq!(via_fft, naive);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least deg(self) + 1")]
+    fn test_to_values_rejects_too_short_domain() {
+        let p = Polynomial::<Mod17>::from(vec![Mod17::new(1), Mod17::new(2), Mod17::new(3)]);
+        p.to_values(2);
+    }
+
+    #[test]
+    fn test_eval_horner() {
+        integers_mod!(IntegerMod7, 7);
+
+        // 1 + 2x + 3x^2, evaluated at x = 2: 1 + 4 + 12 = 17 = 3 (mod 7)
+        let p = Polynomial::<IntegerMod7>::from(vec![
+            IntegerMod7::new(1),
+            IntegerMod7::new(2),
+            IntegerMod7::new(3),
+        ]);
+        assert_eq!(p.eval(IntegerMod7::new(2)), IntegerMod7::new(3));
+        assert_eq!(
+            p.eval_many(&[IntegerMod7::new(0), IntegerMod7::new(2)]),
+            vec![IntegerMod7::new(1), IntegerMod7::new(3)]
+        );
+    }
+
+    #[test]
+    fn test_from_roots() {
+        integers_mod!(IntegerMod7, 7);
+
+        // (x - 1)(x - 2) = x^2 - 3x + 2
+        let p = Polynomial::<IntegerMod7>::from_roots(vec![
+            IntegerMod7::new(1),
+            IntegerMod7::new(2),
+        ]);
+        assert_eq!(
+            p,
+            Polynomial::<IntegerMod7>::from(vec![
+                IntegerMod7::new(2),
+                IntegerMod7::new(4), // -3 mod 7
+                IntegerMod7::new(1),
+            ])
+        );
+        assert_eq!(p.eval(IntegerMod7::new(1)), IntegerMod7::new(0));
+        assert_eq!(p.eval(IntegerMod7::new(2)), IntegerMod7::new(0));
+    }
+
+    #[test]
+    fn test_gcd() {
+        integers_mod!(IntegerMod7, 7);
+
+        // roots {1, 2} and {2, 3} share the root 2, so gcd = (x - 2)
+        let a = Polynomial::<IntegerMod7>::from_roots(vec![
+            IntegerMod7::new(1),
+            IntegerMod7::new(2),
+        ]);
+        let b = Polynomial::<IntegerMod7>::from_roots(vec![
+            IntegerMod7::new(2),
+            IntegerMod7::new(3),
+        ]);
+
+        let g = a.gcd(&b);
+        assert_eq!(
+            g,
+            Polynomial::<IntegerMod7>::from(vec![IntegerMod7::new(5), IntegerMod7::new(1)])
+        );
+    }
+
+    #[test]
+    fn test_extended_gcd_bezout_identity() {
+        integers_mod!(IntegerMod7, 7);
+
+        let a = Polynomial::<IntegerMod7>::from_roots(vec![
+            IntegerMod7::new(1),
+            IntegerMod7::new(2),
+        ]);
+        let b = Polynomial::<IntegerMod7>::from_roots(vec![
+            IntegerMod7::new(2),
+            IntegerMod7::new(3),
+        ]);
+
+        let (g, s, t) = a.extended_gcd(&b);
+        assert_eq!(g, a.gcd(&b));
+
+        // s*a + t*b == g, checked pointwise by evaluating both sides at a few points.
+        for x in [0, 1, 3, 5].map(IntegerMod7::new) {
+            let lhs = (s.clone() * a.clone()).eval(x) + (t.clone() * b.clone()).eval(x);
+            assert_eq!(lhs, g.eval(x));
+        }
+    }
+
+    #[test]
+    fn test_poly_mod_gf4_inverse() {
+        integers_mod!(IntegerMod2, 2);
+        poly_mod!(
+            GF4,
+            IntegerMod2,
+            Polynomial::from(vec![
+                IntegerMod2::new(1),
+                IntegerMod2::new(1),
+                IntegerMod2::new(1),
+            ])
+        );
+
+        // GF(4) = IntegerMod2[x]/(x^2 + x + 1), with x^2 + x + 1 irreducible over GF(2).
+        let x = GF4::new(Polynomial::from(vec![
+            IntegerMod2::new(0),
+            IntegerMod2::new(1),
+        ]));
+        let x_inv = x.inverse();
+
+        assert_eq!(
+            (x.clone() * x_inv).value(),
+            Polynomial::<IntegerMod2>::one()
+        );
+    }
+
+    #[test]
+    fn test_monomial_orders() {
+        // x^2 vs x*y: lex and grlex both put x^2 first (bigger in the leading variable /
+        // equal degree, lex-bigger); grevlex agrees here too since total degree is equal
+        // and the trailing exponent of x^2 (0) is smaller than x*y's (1).
+        let x_sq = vec![2, 0];
+        let xy = vec![1, 1];
+        assert_eq!(
+            compare_monomials(MonomialOrder::Lex, &x_sq, &xy),
+            Ordering::Greater
+        );
+        assert_eq!(
+            compare_monomials(MonomialOrder::GradedLex, &x_sq, &xy),
+            Ordering::Greater
+        );
+        assert_eq!(
+            compare_monomials(MonomialOrder::GradedRevLex, &x_sq, &xy),
+            Ordering::Greater
+        );
+
+        // x^3 (degree 3) beats x*y (degree 2) under every order.
+        let x_cubed = vec![3, 0];
+        assert_eq!(
+            compare_monomials(MonomialOrder::GradedLex, &x_cubed, &xy),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_s_polynomial_self_cancels() {
+        integers_mod!(IntegerMod7, 7);
+
+        let f = MultiPoly::monomial(vec![1, 1], IntegerMod7::new(1), MonomialOrder::GradedLex)
+            + MultiPoly::monomial(vec![0, 0], IntegerMod7::new(6), MonomialOrder::GradedLex);
+
+        assert!(MultiPoly::s_polynomial(&f, &f).is_zero());
+    }
+
+    #[test]
+    fn test_is_member() {
+        integers_mod!(IntegerMod7, 7);
+
+        // ideal generated by x
+        let f = MultiPoly::monomial(vec![1, 0], IntegerMod7::new(1), MonomialOrder::GradedLex);
+        let basis = groebner_basis(&[f.clone()]);
+
+        // x itself is a member
+        assert!(f.is_member(&basis));
+
+        // y is not a multiple of x
+        let g = MultiPoly::monomial(vec![0, 1], IntegerMod7::new(1), MonomialOrder::GradedLex);
+        assert!(!g.is_member(&basis));
+    }
+
+    #[test]
+    fn test_groebner_basis_multi_generator() {
+        integers_mod!(IntegerMod7, 7);
+
+        // {x^2 - y, x^3 - z} in GF(7)[x, y, z] (variables in that order): Buchberger's
+        // algorithm needs to run its S-polynomial/reduce/add-to-basis loop to discover
+        // z - x*y, which is not a multiple of either generator's leading term.
+        let f1 = MultiPoly::monomial(vec![2, 0, 0], IntegerMod7::new(1), MonomialOrder::GradedLex)
+            + MultiPoly::monomial(vec![0, 1, 0], IntegerMod7::new(6), MonomialOrder::GradedLex);
+        let f2 = MultiPoly::monomial(vec![3, 0, 0], IntegerMod7::new(1), MonomialOrder::GradedLex)
+            + MultiPoly::monomial(vec![0, 0, 1], IntegerMod7::new(6), MonomialOrder::GradedLex);
+
+        let basis = groebner_basis(&[f1.clone(), f2.clone()]);
+        assert!(
+            basis.len() > 2,
+            "Buchberger's algorithm should have discovered a new basis element"
+        );
+
+        assert!(f1.is_member(&basis));
+        assert!(f2.is_member(&basis));
+
+        // z - x*y is in the ideal: x*(x^2 - y) - (x^3 - z) = z - x*y.
+        let z_minus_xy = MultiPoly::monomial(vec![0, 0, 1], IntegerMod7::new(1), MonomialOrder::GradedLex)
+            + MultiPoly::monomial(vec![1, 1, 0], IntegerMod7::new(6), MonomialOrder::GradedLex);
+        assert!(z_minus_xy.is_member(&basis));
+
+        // but a generic linear polynomial in just y and z is not.
+        let not_in_ideal =
+            MultiPoly::monomial(vec![0, 1, 0], IntegerMod7::new(1), MonomialOrder::GradedLex)
+                + MultiPoly::monomial(vec![0, 0, 1], IntegerMod7::new(1), MonomialOrder::GradedLex);
+        assert!(!not_in_ideal.is_member(&basis));
+    }
+
+    #[test]
+    fn test_derivative() {
+        integers_mod!(IntegerMod7, 7);
+
+        // d/dx (1 + 2x + 3x^2) = 2 + 6x
+        let p = Polynomial::<IntegerMod7>::from(vec![
+            IntegerMod7::new(1),
+            IntegerMod7::new(2),
+            IntegerMod7::new(3),
+        ]);
+        assert_eq!(
+            p.derivative(),
+            Polynomial::<IntegerMod7>::from(vec![IntegerMod7::new(2), IntegerMod7::new(6)])
+        );
+
+        let constant = Polynomial::<IntegerMod7>::from(vec![IntegerMod7::new(5)]);
+        assert_eq!(constant.derivative(), Polynomial::<IntegerMod7>::zero());
+    }
+
+    #[test]
+    fn test_integral() {
+        integers_mod!(IntegerMod7, 7);
+
+        // integral of (2 + 6x) with zero constant term = 2x + 3x^2
+        let p = Polynomial::<IntegerMod7>::from(vec![IntegerMod7::new(2), IntegerMod7::new(6)]);
+        assert_eq!(
+            p.integral(),
+            Polynomial::<IntegerMod7>::from(vec![
+                IntegerMod7::new(0),
+                IntegerMod7::new(2),
+                IntegerMod7::new(3),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_pow() {
+        integers_mod!(IntegerMod7, 7);
+
+        // (x + 1)^2 = x^2 + 2x + 1
+        let p = Polynomial::<IntegerMod7>::from(vec![IntegerMod7::new(1), IntegerMod7::new(1)]);
+        assert_eq!(
+            p.pow(2),
+            Polynomial::<IntegerMod7>::from(vec![
+                IntegerMod7::new(1),
+                IntegerMod7::new(2),
+                IntegerMod7::new(1),
+            ])
+        );
+        assert_eq!(p.pow(0), Polynomial::<IntegerMod7>::one());
+    }
+
     /*
     #[test]
     fn polynomial_arithmetic() {